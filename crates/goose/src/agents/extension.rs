@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::sandbox::{PlatformSandboxLauncher, SandboxLauncher, SandboxProfile};
+
+/// How an extension is wired up and, for subprocess-backed extensions, what
+/// it's allowed to do once spawned.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExtensionConfig {
+    /// An extension launched as a child process speaking MCP over stdio.
+    Stdio {
+        cmd: String,
+        args: Vec<String>,
+        #[serde(default)]
+        envs: HashMap<String, String>,
+        #[serde(default)]
+        timeout: Option<u64>,
+        /// OS-level sandbox applied to the child process before `exec`.
+        /// `None` means the extension runs unsandboxed (legacy entries).
+        #[serde(default)]
+        sandbox: Option<SandboxProfile>,
+    },
+    /// An extension reached over SSE.
+    Sse {
+        uri: String,
+        #[serde(default)]
+        envs: HashMap<String, String>,
+        #[serde(default)]
+        timeout: Option<u64>,
+    },
+    /// An extension compiled directly into goose.
+    Builtin {
+        name: String,
+        #[serde(default)]
+        timeout: Option<u64>,
+    },
+}
+
+impl ExtensionConfig {
+    pub fn name(&self) -> &str {
+        match self {
+            ExtensionConfig::Stdio { cmd, .. } => cmd,
+            ExtensionConfig::Sse { uri, .. } => uri,
+            ExtensionConfig::Builtin { name, .. } => name,
+        }
+    }
+
+    /// The sandbox profile that should be applied when this extension is
+    /// launched, if any. Only stdio (subprocess) extensions can be sandboxed.
+    pub fn sandbox_profile(&self) -> Option<&SandboxProfile> {
+        match self {
+            ExtensionConfig::Stdio { sandbox, .. } => sandbox.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Builds the not-yet-spawned child process for a `Stdio` extension,
+    /// with its sandbox profile (if any) applied via
+    /// [`PlatformSandboxLauncher`] so the OS enforces it before the child's
+    /// first instruction. Used by
+    /// [`crate::config::extensions::ExtensionConfigManager::launch`], which
+    /// should be the only place a stdio extension is ever actually spawned
+    /// from, rather than building `cmd`/`args` into a `Command` directly and
+    /// bypassing the sandbox.
+    ///
+    /// Returns `None` for non-stdio extensions, which have no process to
+    /// sandbox.
+    pub fn stdio_command(&self) -> Option<std::io::Result<tokio::process::Command>> {
+        let ExtensionConfig::Stdio {
+            cmd,
+            args,
+            envs,
+            sandbox,
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        let mut command = tokio::process::Command::new(cmd);
+        command.args(args).envs(envs);
+
+        Some(match sandbox {
+            Some(profile) => PlatformSandboxLauncher
+                .apply(profile, &mut command)
+                .map(|()| command),
+            None => Ok(command),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stdio_command_is_none_for_non_stdio_extensions() {
+        let builtin = ExtensionConfig::Builtin {
+            name: "developer".to_string(),
+            timeout: None,
+        };
+        assert!(builtin.stdio_command().is_none());
+    }
+
+    #[test]
+    fn stdio_command_skips_sandboxing_when_no_profile_is_set() {
+        let stdio = ExtensionConfig::Stdio {
+            cmd: "true".to_string(),
+            args: vec![],
+            envs: HashMap::new(),
+            timeout: None,
+            sandbox: None,
+        };
+        assert!(stdio.stdio_command().unwrap().is_ok());
+    }
+}
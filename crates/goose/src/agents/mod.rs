@@ -0,0 +1,3 @@
+mod extension;
+
+pub use extension::ExtensionConfig;
@@ -0,0 +1,440 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use etcetera::{AppStrategy, AppStrategyArgs};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+/// App strategy used to locate goose's config directory across platforms.
+pub static APP_STRATEGY: AppStrategyArgs = AppStrategyArgs {
+    top_level_domain: "Block".to_string(),
+    author: "Block".to_string(),
+    app_name: "goose".to_string(),
+};
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("config key not found: {0}")]
+    NotFound(String),
+    #[error("failed to deserialize config value: {0}")]
+    Deserialize(#[from] serde_json::Error),
+    #[error("failed to read/write config file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("secret storage error: {0}")]
+    Secret(String),
+    #[error("no such profile: {0}")]
+    ProfileNotFound(String),
+    #[error("profile already exists: {0}")]
+    ProfileExists(String),
+    #[error("cannot delete the active profile: {0}")]
+    ProfileActive(String),
+}
+
+/// A named set of key/value overrides: provider/model selection, enabled
+/// extensions, permission mode, experiment flags, and anything else a user
+/// stores through [`Config::get`]/[`Config::set`].
+type Profile = HashMap<String, Value>;
+
+/// On-disk shape of the config file. Older, pre-profile files deserialize as
+/// a bare `Profile` map and are migrated into `default` on load.
+#[derive(Serialize, Deserialize, Default)]
+struct ConfigFile {
+    /// Shared values every profile falls back to when it has no override.
+    #[serde(default)]
+    base: Profile,
+    #[serde(default)]
+    profiles: HashMap<String, Profile>,
+    #[serde(default = "default_profile_name")]
+    active_profile: String,
+}
+
+fn default_profile_name() -> String {
+    DEFAULT_PROFILE.to_string()
+}
+
+struct ConfigState {
+    base: Profile,
+    profiles: HashMap<String, Profile>,
+    active_profile: String,
+}
+
+/// Central, file-backed configuration store for goose.
+///
+/// Non-secret values live in a JSON document on disk, split into a
+/// shared `base` layer and any number of named profiles; secrets (API keys,
+/// tokens) are kept out of that file and go through the OS keyring instead.
+pub struct Config {
+    path: PathBuf,
+    state: Mutex<ConfigState>,
+}
+
+static GLOBAL: OnceLock<Config> = OnceLock::new();
+
+impl Config {
+    pub fn global() -> &'static Config {
+        GLOBAL.get_or_init(|| {
+            let strategy = etcetera::choose_app_strategy(APP_STRATEGY.clone())
+                .expect("failed to resolve config directory");
+            let path = strategy.config_dir().join("config.json");
+            Config::load_from(path).expect("failed to load goose config")
+        })
+    }
+
+    pub fn load_from(path: PathBuf) -> Result<Self, ConfigError> {
+        let state = if path.exists() {
+            let raw = fs::read_to_string(&path)?;
+            Self::parse(&raw)?
+        } else {
+            ConfigState {
+                base: Profile::new(),
+                profiles: HashMap::from([(DEFAULT_PROFILE.to_string(), Profile::new())]),
+                active_profile: DEFAULT_PROFILE.to_string(),
+            }
+        };
+        Ok(Config {
+            path,
+            state: Mutex::new(state),
+        })
+    }
+
+    /// Parses either the current `{ base, profiles, active_profile }` shape
+    /// or a legacy flat key/value file, migrating the latter into a single
+    /// `default` profile.
+    fn parse(raw: &str) -> Result<ConfigState, ConfigError> {
+        if let Ok(file) = serde_json::from_str::<ConfigFile>(raw) {
+            if !file.profiles.is_empty() {
+                return Ok(ConfigState {
+                    base: file.base,
+                    profiles: file.profiles,
+                    active_profile: file.active_profile,
+                });
+            }
+        }
+        let legacy: Profile = serde_json::from_str(raw)?;
+        Ok(ConfigState {
+            base: Profile::new(),
+            profiles: HashMap::from([(DEFAULT_PROFILE.to_string(), legacy)]),
+            active_profile: DEFAULT_PROFILE.to_string(),
+        })
+    }
+
+    /// Resolve a key, preferring an environment variable override
+    /// (`GOOSE_<SCREAMING_SNAKE_KEY>`), then the active profile, then the
+    /// shared base layer.
+    ///
+    /// The env var is parsed as JSON first (so it can override non-string
+    /// keys like a `bool`, a `Vec<ExtensionEntry>`, or a permission map),
+    /// falling back to the raw string if it isn't valid JSON (so a plain
+    /// `GOOSE_PROVIDER=openrouter` still works for `T = String`). If the
+    /// parsed value doesn't deserialize as `T`, the override is ignored
+    /// rather than failing `get()` outright, and resolution falls through
+    /// to the profile/base layers.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<T, ConfigError> {
+        let env_key = format!("GOOSE_{}", key.to_uppercase());
+        if let Ok(raw) = std::env::var(&env_key) {
+            let value = serde_json::from_str(&raw).unwrap_or(Value::String(raw));
+            if let Ok(parsed) = serde_json::from_value(value) {
+                return Ok(parsed);
+            }
+        }
+        let state = self.state.lock().unwrap();
+        let active = state.profiles.get(&state.active_profile);
+        let value = active
+            .and_then(|p| p.get(key))
+            .or_else(|| state.base.get(key))
+            .cloned()
+            .ok_or_else(|| ConfigError::NotFound(key.to_string()))?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Set a key in the active profile.
+    pub fn set<T: Serialize>(&self, key: &str, value: T) -> Result<(), ConfigError> {
+        let mut state = self.state.lock().unwrap();
+        let active_profile = state.active_profile.clone();
+        let profile = state
+            .profiles
+            .entry(active_profile)
+            .or_insert_with(Profile::new);
+        profile.insert(key.to_string(), serde_json::to_value(value)?);
+        self.persist(&state)
+    }
+
+    /// Read a key from the shared base layer directly, bypassing the active
+    /// profile. Mostly useful for inspecting/migrating base values; prefer
+    /// [`Config::get`] to resolve a key the normal profile-then-base way.
+    pub fn get_base<T: DeserializeOwned>(&self, key: &str) -> Result<T, ConfigError> {
+        let state = self.state.lock().unwrap();
+        let value = state
+            .base
+            .get(key)
+            .cloned()
+            .ok_or_else(|| ConfigError::NotFound(key.to_string()))?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Set a key in the shared base layer, visible to every profile that
+    /// doesn't override it.
+    pub fn set_base<T: Serialize>(&self, key: &str, value: T) -> Result<(), ConfigError> {
+        let mut state = self.state.lock().unwrap();
+        state
+            .base
+            .insert(key.to_string(), serde_json::to_value(value)?);
+        self.persist(&state)
+    }
+
+    pub fn delete(&self, key: &str) -> Result<(), ConfigError> {
+        let mut state = self.state.lock().unwrap();
+        let active_profile = state.active_profile.clone();
+        if let Some(profile) = state.profiles.get_mut(&active_profile) {
+            profile.remove(key);
+        }
+        self.persist(&state)
+    }
+
+    pub fn active_profile(&self) -> String {
+        self.state.lock().unwrap().active_profile.clone()
+    }
+
+    pub fn list_profiles(&self) -> Vec<String> {
+        self.state.lock().unwrap().profiles.keys().cloned().collect()
+    }
+
+    pub fn create_profile(&self, name: &str) -> Result<(), ConfigError> {
+        let mut state = self.state.lock().unwrap();
+        if state.profiles.contains_key(name) {
+            return Err(ConfigError::ProfileExists(name.to_string()));
+        }
+        state.profiles.insert(name.to_string(), Profile::new());
+        self.persist(&state)
+    }
+
+    pub fn clone_profile(&self, source: &str, dest: &str) -> Result<(), ConfigError> {
+        let mut state = self.state.lock().unwrap();
+        if state.profiles.contains_key(dest) {
+            return Err(ConfigError::ProfileExists(dest.to_string()));
+        }
+        let source_values = state
+            .profiles
+            .get(source)
+            .ok_or_else(|| ConfigError::ProfileNotFound(source.to_string()))?
+            .clone();
+        state.profiles.insert(dest.to_string(), source_values);
+        self.persist(&state)
+    }
+
+    pub fn delete_profile(&self, name: &str) -> Result<(), ConfigError> {
+        let mut state = self.state.lock().unwrap();
+        if name == state.active_profile {
+            return Err(ConfigError::ProfileActive(name.to_string()));
+        }
+        if state.profiles.remove(name).is_none() {
+            return Err(ConfigError::ProfileNotFound(name.to_string()));
+        }
+        self.persist(&state)
+    }
+
+    /// Switch the active profile, repointing every `Config::get`/`set` call
+    /// (and therefore every manager built on top of `Config`) at it.
+    pub fn switch_profile(&self, name: &str) -> Result<(), ConfigError> {
+        let mut state = self.state.lock().unwrap();
+        if !state.profiles.contains_key(name) {
+            return Err(ConfigError::ProfileNotFound(name.to_string()));
+        }
+        state.active_profile = name.to_string();
+        self.persist(&state)
+    }
+
+    /// Store a secret (API key, OAuth token, ...) in the OS keyring rather
+    /// than in the config file. Keyed by the active profile, so `work` and
+    /// `personal` profiles can hold different credentials for the same
+    /// logical key.
+    pub fn set_secret(&self, key: &str, value: &str) -> Result<(), ConfigError> {
+        let entry = keyring::Entry::new("goose", &self.secret_key(key))
+            .map_err(|e| ConfigError::Secret(e.to_string()))?;
+        entry
+            .set_password(value)
+            .map_err(|e| ConfigError::Secret(e.to_string()))
+    }
+
+    pub fn get_secret(&self, key: &str) -> Result<String, ConfigError> {
+        let entry = keyring::Entry::new("goose", &self.secret_key(key))
+            .map_err(|e| ConfigError::Secret(e.to_string()))?;
+        entry
+            .get_password()
+            .map_err(|e| ConfigError::Secret(e.to_string()))
+    }
+
+    fn secret_key(&self, key: &str) -> String {
+        format!("{}::{}", self.active_profile(), key)
+    }
+
+    fn persist(&self, state: &ConfigState) -> Result<(), ConfigError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = ConfigFile {
+            base: state.base.clone(),
+            profiles: state.profiles.clone(),
+            active_profile: state.active_profile.clone(),
+        };
+        let raw = serde_json::to_string_pretty(&file)?;
+        fs::write(&self.path, raw)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_flat_file_migrates_into_default_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(&path, r#"{"provider": "openrouter"}"#).unwrap();
+
+        let config = Config::load_from(path).unwrap();
+
+        assert_eq!(config.active_profile(), DEFAULT_PROFILE);
+        assert_eq!(config.get::<String>("provider").unwrap(), "openrouter");
+    }
+
+    #[test]
+    fn switch_profile_rejects_unknown_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+
+        let err = config.switch_profile("ghost").unwrap_err();
+
+        assert!(matches!(err, ConfigError::ProfileNotFound(name) if name == "ghost"));
+    }
+
+    #[test]
+    fn delete_profile_rejects_the_active_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+
+        let err = config.delete_profile(DEFAULT_PROFILE).unwrap_err();
+
+        assert!(matches!(err, ConfigError::ProfileActive(name) if name == DEFAULT_PROFILE));
+    }
+
+    #[test]
+    fn delete_profile_rejects_unknown_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+
+        let err = config.delete_profile("ghost").unwrap_err();
+
+        assert!(matches!(err, ConfigError::ProfileNotFound(name) if name == "ghost"));
+    }
+
+    #[test]
+    fn create_profile_adds_an_empty_profile_to_the_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+
+        config.create_profile("work").unwrap();
+
+        assert!(config.list_profiles().contains(&"work".to_string()));
+        assert!(matches!(
+            config.create_profile("work").unwrap_err(),
+            ConfigError::ProfileExists(name) if name == "work"
+        ));
+    }
+
+    #[test]
+    fn clone_profile_copies_the_source_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+        config.set("provider", "openrouter").unwrap();
+
+        config.clone_profile(DEFAULT_PROFILE, "work").unwrap();
+        config.switch_profile("work").unwrap();
+
+        assert_eq!(config.get::<String>("provider").unwrap(), "openrouter");
+    }
+
+    #[test]
+    fn switch_profile_repoints_get_and_set_at_the_new_active_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+        config.set("provider", "openrouter").unwrap();
+        config.create_profile("work").unwrap();
+
+        config.switch_profile("work").unwrap();
+
+        // "work" has no override for "provider", and there's no base layer
+        // value either, so it no longer resolves to the default profile's
+        // value.
+        assert!(matches!(
+            config.get::<String>("provider").unwrap_err(),
+            ConfigError::NotFound(key) if key == "provider"
+        ));
+
+        config.set("provider", "anthropic").unwrap();
+        assert_eq!(config.get::<String>("provider").unwrap(), "anthropic");
+
+        config.switch_profile(DEFAULT_PROFILE).unwrap();
+        assert_eq!(config.get::<String>("provider").unwrap(), "openrouter");
+    }
+
+    #[test]
+    fn get_falls_back_to_base_layer() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+
+        config.set_base("shared_key", "from_base").unwrap();
+
+        assert_eq!(config.get::<String>("shared_key").unwrap(), "from_base");
+        assert_eq!(config.get_base::<String>("shared_key").unwrap(), "from_base");
+    }
+
+    #[test]
+    fn get_env_override_parses_non_string_types() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+        let env_key = "GOOSE_TEST_ENABLE_THING";
+
+        std::env::set_var(env_key, "true");
+        let result = config.get::<bool>("test_enable_thing");
+        std::env::remove_var(env_key);
+
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn get_env_override_parses_json_arrays() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+        let env_key = "GOOSE_TEST_TAGS";
+
+        std::env::set_var(env_key, r#"["a","b"]"#);
+        let result = config.get::<Vec<String>>("test_tags");
+        std::env::remove_var(env_key);
+
+        assert_eq!(result.unwrap(), vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn get_env_override_ignores_a_mismatched_value_and_falls_through() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(dir.path().join("config.json")).unwrap();
+        config.set("test_mismatch", vec!["from_profile".to_string()]).unwrap();
+        let env_key = "GOOSE_TEST_MISMATCH";
+
+        // Not valid JSON for a `Vec<String>`, so the override is skipped
+        // rather than failing `get()` outright.
+        std::env::set_var(env_key, "not json");
+        let result = config.get::<Vec<String>>("test_mismatch");
+        std::env::remove_var(env_key);
+
+        assert_eq!(result.unwrap(), vec!["from_profile".to_string()]);
+    }
+}
@@ -0,0 +1,24 @@
+use super::base::{Config, ConfigError};
+
+const EXPERIMENTS_KEY: &str = "experiments";
+
+/// Feature flags users can opt into before they're generally available.
+pub struct ExperimentManager;
+
+impl ExperimentManager {
+    pub fn is_enabled(config: &Config, name: &str) -> bool {
+        config
+            .get::<std::collections::HashMap<String, bool>>(EXPERIMENTS_KEY)
+            .ok()
+            .and_then(|flags| flags.get(name).copied())
+            .unwrap_or(false)
+    }
+
+    pub fn set_enabled(config: &Config, name: &str, enabled: bool) -> Result<(), ConfigError> {
+        let mut flags = config
+            .get::<std::collections::HashMap<String, bool>>(EXPERIMENTS_KEY)
+            .unwrap_or_default();
+        flags.insert(name.to_string(), enabled);
+        config.set(EXPERIMENTS_KEY, flags)
+    }
+}
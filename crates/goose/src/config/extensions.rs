@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+
+use super::base::{Config, ConfigError};
+use super::sandbox::SandboxProfile;
+use crate::agents::ExtensionConfig;
+
+pub const DEFAULT_EXTENSION: &str = "developer";
+pub const DEFAULT_DISPLAY_NAME: &str = "Developer";
+pub const DEFAULT_EXTENSION_DESCRIPTION: &str = "General developer tools useful for software development";
+pub const DEFAULT_EXTENSION_TIMEOUT: u64 = 300;
+
+const EXTENSIONS_KEY: &str = "extensions";
+
+/// A single configured extension: whether it's turned on and how to reach it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExtensionEntry {
+    pub enabled: bool,
+    #[serde(flatten)]
+    pub config: ExtensionConfig,
+}
+
+/// Reads and writes the `extensions` map in [`Config`].
+pub struct ExtensionConfigManager;
+
+impl ExtensionConfigManager {
+    pub fn get_all(config: &Config) -> Result<Vec<ExtensionEntry>, ConfigError> {
+        match config.get::<Vec<ExtensionEntry>>(EXTENSIONS_KEY) {
+            Ok(entries) => Ok(entries),
+            Err(ConfigError::NotFound(_)) => Ok(Vec::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn set(config: &Config, entry: ExtensionEntry) -> Result<(), ConfigError> {
+        Self::validate(&entry);
+        let mut entries = Self::get_all(config)?;
+        entries.retain(|e| e.config.name() != entry.config.name());
+        entries.push(entry);
+        config.set(EXTENSIONS_KEY, entries)
+    }
+
+    pub fn remove(config: &Config, name: &str) -> Result<(), ConfigError> {
+        let mut entries = Self::get_all(config)?;
+        entries.retain(|e| e.config.name() != name);
+        config.set(EXTENSIONS_KEY, entries)
+    }
+
+    /// Spawns `entry`'s process, applying its sandbox profile (if any) via
+    /// [`ExtensionConfig::stdio_command`] before the child's first
+    /// instruction. This is the one place that should ever launch a stdio
+    /// extension; building a `Command` from `entry.config` directly anywhere
+    /// else bypasses the sandbox entirely.
+    ///
+    /// Also re-runs [`Self::validate`]'s warning here, not just in
+    /// [`Self::set`]: entries loaded straight off disk via [`Self::get_all`]
+    /// at startup never went through `set`, so this is the only point
+    /// guaranteed to fire for every extension that's actually launched.
+    ///
+    /// Returns `Ok(None)` for non-stdio extensions, which have no process to
+    /// launch.
+    pub fn launch(entry: &ExtensionEntry) -> std::io::Result<Option<tokio::process::Child>> {
+        Self::validate(entry);
+        match entry.config.stdio_command() {
+            None => Ok(None),
+            Some(Err(err)) => Err(err),
+            Some(Ok(mut command)) => command.spawn().map(Some),
+        }
+    }
+
+    /// Warn (but don't fail) when a stdio extension has no sandbox profile,
+    /// so existing profile-less entries keep round-tripping as "unsandboxed".
+    fn validate(entry: &ExtensionEntry) {
+        if let ExtensionConfig::Stdio { sandbox, cmd, .. } = &entry.config {
+            match sandbox {
+                Some(SandboxProfile { allow, .. }) if allow.is_empty() => {
+                    tracing::warn!(
+                        extension = cmd,
+                        "sandbox profile has an empty allowlist; extension will be fully denied"
+                    );
+                }
+                None => {
+                    tracing::warn!(
+                        extension = cmd,
+                        "no sandbox profile set; extension will run unsandboxed"
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::sandbox::SandboxOperation;
+
+    fn config_at(dir: &std::path::Path, name: &str) -> Config {
+        Config::load_from(dir.join(name)).unwrap()
+    }
+
+    fn stdio_entry(cmd: &str, sandbox: Option<SandboxProfile>) -> ExtensionEntry {
+        ExtensionEntry {
+            enabled: true,
+            config: ExtensionConfig::Stdio {
+                cmd: cmd.to_string(),
+                args: Vec::new(),
+                envs: Default::default(),
+                timeout: None,
+                sandbox,
+            },
+        }
+    }
+
+    #[test]
+    fn set_overwrites_an_existing_entry_by_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config_at(dir.path(), "config.json");
+        ExtensionConfigManager::set(&config, stdio_entry("foo", None)).unwrap();
+
+        let mut updated = stdio_entry("foo", None);
+        updated.enabled = false;
+        ExtensionConfigManager::set(&config, updated).unwrap();
+
+        let entries = ExtensionConfigManager::get_all(&config).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(!entries[0].enabled);
+    }
+
+    #[test]
+    fn validate_accepts_an_entry_with_no_sandbox_profile() {
+        // No profile is the legacy, unsandboxed case; `set` must not fail it.
+        let dir = tempfile::tempdir().unwrap();
+        let config = config_at(dir.path(), "config.json");
+
+        ExtensionConfigManager::set(&config, stdio_entry("foo", None)).unwrap();
+
+        assert_eq!(ExtensionConfigManager::get_all(&config).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn validate_accepts_an_entry_with_an_empty_allowlist() {
+        // Fully denied (not unsandboxed), but still a valid, round-trippable entry.
+        let dir = tempfile::tempdir().unwrap();
+        let config = config_at(dir.path(), "config.json");
+
+        ExtensionConfigManager::set(&config, stdio_entry("foo", Some(SandboxProfile::new(vec![]))))
+            .unwrap();
+
+        let entries = ExtensionConfigManager::get_all(&config).unwrap();
+        assert_eq!(entries[0].config.sandbox_profile().unwrap().allow, Vec::new());
+    }
+
+    #[test]
+    fn validate_accepts_an_entry_with_a_non_empty_allowlist() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config_at(dir.path(), "config.json");
+        let sandbox = SandboxProfile::new(vec![SandboxOperation::SystemInfoRead]);
+
+        ExtensionConfigManager::set(&config, stdio_entry("foo", Some(sandbox.clone()))).unwrap();
+
+        let entries = ExtensionConfigManager::get_all(&config).unwrap();
+        assert_eq!(entries[0].config.sandbox_profile(), Some(&sandbox));
+    }
+
+    #[tokio::test]
+    async fn launch_spawns_a_configured_stdio_extension() {
+        let mut child = ExtensionConfigManager::launch(&stdio_entry("true", None))
+            .unwrap()
+            .expect("stdio extensions have a process to launch");
+        assert!(child.wait().await.unwrap().success());
+    }
+
+    #[test]
+    fn launch_returns_none_for_a_non_stdio_extension() {
+        let entry = ExtensionEntry {
+            enabled: true,
+            config: ExtensionConfig::Builtin {
+                name: "developer".to_string(),
+                timeout: None,
+            },
+        };
+        assert!(ExtensionConfigManager::launch(&entry).unwrap().is_none());
+    }
+}
@@ -2,14 +2,18 @@ pub mod base;
 mod experiments;
 pub mod extensions;
 pub mod permission;
-pub mod signup_openrouter;
+pub mod sandbox;
+pub mod scripts;
+pub mod signup;
 
 pub use crate::agents::ExtensionConfig;
 pub use base::{Config, ConfigError, APP_STRATEGY};
 pub use experiments::ExperimentManager;
 pub use extensions::{ExtensionConfigManager, ExtensionEntry};
 pub use permission::PermissionManager;
-pub use signup_openrouter::configure_openrouter;
+pub use sandbox::{SandboxLauncher, SandboxOperation, SandboxProfile};
+pub use scripts::{ScriptContext, ScriptEntry, ScriptManager, ScriptTrigger};
+pub use signup::{available as available_signups, OpenRouterSignup, ProviderCredentials, ProviderSignup, SignupError};
 
 pub use extensions::DEFAULT_DISPLAY_NAME;
 pub use extensions::DEFAULT_EXTENSION;
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use super::base::{Config, ConfigError};
+
+const PERMISSION_KEY: &str = "tool_permissions";
+
+/// How much confirmation a tool call needs before it runs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionLevel {
+    AlwaysAllow,
+    AskBefore,
+    NeverAllow,
+}
+
+/// Per-tool permission gates, keyed by tool name, persisted in [`Config`].
+pub struct PermissionManager;
+
+impl PermissionManager {
+    pub fn get(config: &Config, tool_name: &str) -> PermissionLevel {
+        config
+            .get::<std::collections::HashMap<String, PermissionLevel>>(PERMISSION_KEY)
+            .ok()
+            .and_then(|levels| levels.get(tool_name).copied())
+            .unwrap_or(PermissionLevel::AskBefore)
+    }
+
+    pub fn set(
+        config: &Config,
+        tool_name: &str,
+        level: PermissionLevel,
+    ) -> Result<(), ConfigError> {
+        let mut levels = config
+            .get::<std::collections::HashMap<String, PermissionLevel>>(PERMISSION_KEY)
+            .unwrap_or_default();
+        levels.insert(tool_name.to_string(), level);
+        config.set(PERMISSION_KEY, levels)
+    }
+}
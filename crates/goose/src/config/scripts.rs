@@ -0,0 +1,259 @@
+use std::process::Stdio;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::process::Command;
+
+use super::base::{Config, ConfigError};
+
+const SCRIPTS_KEY: &str = "lifecycle_scripts";
+
+/// Points in the agent lifecycle a user script can hook into.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptTrigger {
+    PreSession,
+    PostSession,
+    PreToolCall,
+    PostToolCall,
+    OnError,
+}
+
+impl ScriptTrigger {
+    /// `pre_*` triggers can veto the action they guard; a non-zero exit
+    /// aborts it.
+    fn is_blocking(self) -> bool {
+        matches!(self, ScriptTrigger::PreSession | ScriptTrigger::PreToolCall)
+    }
+}
+
+/// How to run a script's body.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Interpreter {
+    Sh,
+    Python,
+}
+
+impl Interpreter {
+    fn program(self) -> &'static str {
+        match self {
+            Interpreter::Sh => "sh",
+            Interpreter::Python => "python3",
+        }
+    }
+}
+
+/// Where a script's source comes from: inline in config, or a path on disk.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ScriptSource {
+    Inline(String),
+    Path(String),
+}
+
+/// A single user-registered lifecycle hook.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScriptEntry {
+    pub id: String,
+    pub name: String,
+    pub trigger: ScriptTrigger,
+    pub interpreter: Interpreter,
+    pub source: ScriptSource,
+    /// Lower runs first; ties broken by registration order.
+    pub order: i32,
+}
+
+/// Information handed to a script about the event that triggered it.
+/// Passed through as environment variables (`GOOSE_SESSION_ID`,
+/// `GOOSE_TOOL_NAME`, `GOOSE_TOOL_ARGS`) rather than argv or stdin, so
+/// scripts in any language can read them the same way.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptContext {
+    pub session_id: String,
+    pub tool_name: Option<String>,
+    pub tool_args: Option<String>,
+}
+
+impl ScriptContext {
+    fn env_vars(&self) -> Vec<(String, String)> {
+        let mut vars = vec![("GOOSE_SESSION_ID".to_string(), self.session_id.clone())];
+        if let Some(tool_name) = &self.tool_name {
+            vars.push(("GOOSE_TOOL_NAME".to_string(), tool_name.clone()));
+        }
+        if let Some(tool_args) = &self.tool_args {
+            vars.push(("GOOSE_TOOL_ARGS".to_string(), tool_args.clone()));
+        }
+        vars
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ScriptError {
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error("failed to launch script {id}: {source}")]
+    Spawn {
+        id: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("pre-hook script {id} aborted the action (exit code {code}): {stderr}")]
+    Aborted {
+        id: String,
+        code: i32,
+        stdout: String,
+        stderr: String,
+    },
+}
+
+/// Manages user-defined scripts bound to agent lifecycle hooks, persisted
+/// alongside [`super::permission::PermissionManager`] and
+/// [`super::experiments::ExperimentManager`] entries in [`Config`].
+pub struct ScriptManager;
+
+impl ScriptManager {
+    pub fn list(config: &Config) -> Result<Vec<ScriptEntry>, ScriptError> {
+        match config.get::<Vec<ScriptEntry>>(SCRIPTS_KEY) {
+            Ok(entries) => Ok(entries),
+            Err(ConfigError::NotFound(_)) => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn register(config: &Config, entry: ScriptEntry) -> Result<(), ScriptError> {
+        let mut entries = Self::list(config)?;
+        entries.retain(|e| e.id != entry.id);
+        entries.push(entry);
+        config.set(SCRIPTS_KEY, entries).map_err(Into::into)
+    }
+
+    pub fn remove(config: &Config, id: &str) -> Result<(), ScriptError> {
+        let mut entries = Self::list(config)?;
+        entries.retain(|e| e.id != id);
+        config.set(SCRIPTS_KEY, entries).map_err(Into::into)
+    }
+
+    /// Run every script registered for `trigger`, in `order`. A non-zero
+    /// exit from a `pre_*` script stops the run and returns
+    /// [`ScriptError::Aborted`]; other triggers log a failure and continue.
+    pub async fn run_for(
+        config: &Config,
+        trigger: ScriptTrigger,
+        context: &ScriptContext,
+    ) -> Result<(), ScriptError> {
+        let mut entries: Vec<_> = Self::list(config)?
+            .into_iter()
+            .filter(|e| e.trigger == trigger)
+            .collect();
+        entries.sort_by_key(|e| e.order);
+
+        for entry in entries {
+            let output = Self::run_one(&entry, context).await?;
+            if output.status.success() {
+                continue;
+            }
+            let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+            let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+            if trigger.is_blocking() {
+                return Err(ScriptError::Aborted {
+                    id: entry.id,
+                    code: output.status.code().unwrap_or(-1),
+                    stdout,
+                    stderr,
+                });
+            }
+            tracing::warn!(
+                script = entry.id,
+                stdout,
+                stderr,
+                "lifecycle script exited non-zero"
+            );
+        }
+        Ok(())
+    }
+
+    async fn run_one(
+        entry: &ScriptEntry,
+        context: &ScriptContext,
+    ) -> Result<std::process::Output, ScriptError> {
+        let mut cmd = Command::new(entry.interpreter.program());
+        match &entry.source {
+            ScriptSource::Path(path) => {
+                cmd.arg(path);
+            }
+            ScriptSource::Inline(body) => {
+                cmd.arg("-c").arg(body);
+            }
+        }
+        cmd.envs(context.env_vars())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        cmd.output().await.map_err(|source| ScriptError::Spawn {
+            id: entry.id.clone(),
+            source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failing_entry(id: &str, trigger: ScriptTrigger) -> ScriptEntry {
+        ScriptEntry {
+            id: id.to_string(),
+            name: id.to_string(),
+            trigger,
+            interpreter: Interpreter::Sh,
+            source: ScriptSource::Inline("exit 1".to_string()),
+            order: 0,
+        }
+    }
+
+    fn config_at(dir: &std::path::Path, name: &str) -> Config {
+        Config::load_from(dir.join(name)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn run_for_aborts_on_failing_pre_tool_call_script() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config_at(dir.path(), "pre.json");
+        ScriptManager::register(&config, failing_entry("guard", ScriptTrigger::PreToolCall))
+            .unwrap();
+
+        let err = ScriptManager::run_for(
+            &config,
+            ScriptTrigger::PreToolCall,
+            &ScriptContext {
+                session_id: "s1".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, ScriptError::Aborted { ref id, code: 1, .. } if id == "guard"));
+    }
+
+    #[tokio::test]
+    async fn run_for_continues_past_failing_post_tool_call_script() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config_at(dir.path(), "post.json");
+        ScriptManager::register(&config, failing_entry("notify", ScriptTrigger::PostToolCall))
+            .unwrap();
+
+        let result = ScriptManager::run_for(
+            &config,
+            ScriptTrigger::PostToolCall,
+            &ScriptContext {
+                session_id: "s1".to_string(),
+                ..Default::default()
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+
+use super::{ProviderCredentials, ProviderSignup, SignupError, VerificationPrompt};
+
+/// Scaffolding for providers onboarded by pasting an API key rather than a
+/// device-code OAuth dance. `begin()` just returns instructions for where to
+/// find the key; `complete()` takes what the user pastes and turns it
+/// straight into credentials.
+pub struct ApiKeySignup {
+    id: &'static str,
+    display_name: &'static str,
+    instructions: &'static str,
+    default_model: &'static str,
+}
+
+impl ApiKeySignup {
+    pub fn anthropic() -> Self {
+        Self {
+            id: "anthropic",
+            display_name: "Anthropic",
+            instructions: "Paste an API key from https://console.anthropic.com/settings/keys",
+            default_model: "claude-sonnet-4-5",
+        }
+    }
+}
+
+#[async_trait]
+impl ProviderSignup for ApiKeySignup {
+    fn id(&self) -> &str {
+        self.id
+    }
+
+    fn display_name(&self) -> &str {
+        self.display_name
+    }
+
+    async fn begin(&self) -> Result<VerificationPrompt, SignupError> {
+        Ok(VerificationPrompt::ApiKey {
+            instructions: self.instructions.to_string(),
+        })
+    }
+
+    async fn complete(&self, key: &str) -> Result<ProviderCredentials, SignupError> {
+        if key.trim().is_empty() {
+            return Err(SignupError::Rejected);
+        }
+        Ok(ProviderCredentials {
+            provider: self.id.to_string(),
+            default_model: self.default_model.to_string(),
+            secret: key.trim().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn complete_rejects_empty_and_whitespace_keys() {
+        let signup = ApiKeySignup::anthropic();
+
+        assert!(matches!(
+            signup.complete("").await,
+            Err(SignupError::Rejected)
+        ));
+        assert!(matches!(
+            signup.complete("   \n\t").await,
+            Err(SignupError::Rejected)
+        ));
+    }
+
+    #[tokio::test]
+    async fn complete_trims_and_accepts_a_real_key() {
+        let signup = ApiKeySignup::anthropic();
+
+        let credentials = signup.complete("  sk-ant-abc123  ").await.unwrap();
+
+        assert_eq!(credentials.provider, "anthropic");
+        assert_eq!(credentials.default_model, "claude-sonnet-4-5");
+        assert_eq!(credentials.secret, "sk-ant-abc123");
+    }
+}
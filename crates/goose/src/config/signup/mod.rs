@@ -0,0 +1,108 @@
+mod api_key;
+mod openrouter;
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+pub use api_key::ApiKeySignup;
+pub use openrouter::OpenRouterSignup;
+
+use super::base::{Config, ConfigError};
+
+#[derive(Debug, Error)]
+pub enum SignupError {
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error("onboarding request failed: {0}")]
+    Request(String),
+    #[error("user code or key was rejected by the provider")]
+    Rejected,
+}
+
+/// What the user needs to do to continue onboarding, returned by
+/// [`ProviderSignup::begin`].
+#[derive(Debug, Clone)]
+pub enum VerificationPrompt {
+    /// Device-code OAuth: show the user a URL and a short code to enter there.
+    DeviceCode { verification_url: String, user_code: String },
+    /// Browser-callback OAuth: a browser tab was opened at `signup_url`; the
+    /// provider calls back into goose with credentials once the user finishes
+    /// there, so there's no code for the user to type in.
+    BrowserCallback { signup_url: String },
+    /// Key-based onboarding: ask the user to paste a key they fetch elsewhere.
+    ApiKey { instructions: String },
+}
+
+/// Result of polling an in-progress device-code flow.
+#[derive(Debug, Clone)]
+pub enum PollOutcome {
+    Pending,
+    Complete(ProviderCredentials),
+}
+
+/// Credentials resolved at the end of a signup flow, ready to persist.
+#[derive(Debug, Clone)]
+pub struct ProviderCredentials {
+    pub provider: String,
+    pub default_model: String,
+    pub secret: String,
+}
+
+/// One provider's onboarding flow: how to start it, how to see it through to
+/// credentials, and how those credentials get written into [`Config`].
+///
+/// Device-code providers drive `begin()` then poll `poll()` until it
+/// resolves; key-based providers drive `begin()` then hand the pasted key
+/// straight to `complete()`.
+#[async_trait]
+pub trait ProviderSignup: Send + Sync {
+    fn id(&self) -> &str;
+    fn display_name(&self) -> &str;
+
+    async fn begin(&self) -> Result<VerificationPrompt, SignupError>;
+
+    /// Poll a device-code flow for completion. Key-based providers can leave
+    /// this at the default, which never resolves on its own.
+    async fn poll(&self) -> Result<PollOutcome, SignupError> {
+        Ok(PollOutcome::Pending)
+    }
+
+    /// Exchange a user-supplied key for credentials. Device-code providers
+    /// resolve credentials through `poll()` instead and can leave this
+    /// unimplemented.
+    async fn complete(&self, _key: &str) -> Result<ProviderCredentials, SignupError> {
+        Err(SignupError::Rejected)
+    }
+
+    fn persist(&self, config: &Config, credentials: &ProviderCredentials) -> Result<(), ConfigError> {
+        config.set_secret(&format!("{}_api_key", credentials.provider), &credentials.secret)?;
+        config.set("provider", &credentials.provider)?;
+        config.set(
+            &format!("{}_model", credentials.provider),
+            &credentials.default_model,
+        )
+    }
+}
+
+/// Every onboarding flow goose ships, so the onboarding command can list
+/// providers instead of hard-coding one.
+pub fn available() -> Vec<Box<dyn ProviderSignup>> {
+    vec![Box::new(OpenRouterSignup), Box::new(ApiKeySignup::anthropic())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn available_lists_every_shipped_provider_with_no_duplicate_ids() {
+        let ids: Vec<&str> = available().iter().map(|signup| signup.id()).collect();
+
+        assert_eq!(ids, vec!["openrouter", "anthropic"]);
+
+        let mut deduped = ids.clone();
+        deduped.sort_unstable();
+        deduped.dedup();
+        assert_eq!(deduped.len(), ids.len(), "duplicate provider id in available()");
+    }
+}
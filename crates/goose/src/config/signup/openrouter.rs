@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use super::{PollOutcome, ProviderCredentials, ProviderSignup, SignupError, VerificationPrompt};
+
+pub const OPENROUTER_SIGNUP_URL: &str =
+    "https://openrouter.ai/auth?callback_url=goose://provider/openrouter";
+pub const OPENROUTER_DEFAULT_MODEL: &str = "openrouter/auto";
+
+/// OpenRouter's browser-callback OAuth flow: open the auth page, then poll
+/// until the browser callback hands back an API key.
+pub struct OpenRouterSignup;
+
+#[async_trait]
+impl ProviderSignup for OpenRouterSignup {
+    fn id(&self) -> &str {
+        "openrouter"
+    }
+
+    fn display_name(&self) -> &str {
+        "OpenRouter"
+    }
+
+    async fn begin(&self) -> Result<VerificationPrompt, SignupError> {
+        webbrowser::open(OPENROUTER_SIGNUP_URL).ok();
+        Ok(VerificationPrompt::BrowserCallback {
+            signup_url: OPENROUTER_SIGNUP_URL.to_string(),
+        })
+    }
+
+    async fn poll(&self) -> Result<PollOutcome, SignupError> {
+        match goose_auth_callback::wait_for_key("openrouter").await {
+            Ok(api_key) => Ok(PollOutcome::Complete(ProviderCredentials {
+                provider: self.id().to_string(),
+                default_model: OPENROUTER_DEFAULT_MODEL.to_string(),
+                secret: api_key,
+            })),
+            Err(goose_auth_callback::WaitError::Pending) => Ok(PollOutcome::Pending),
+            Err(e) => Err(SignupError::Request(e.to_string())),
+        }
+    }
+}